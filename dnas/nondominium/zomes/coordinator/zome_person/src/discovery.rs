@@ -0,0 +1,68 @@
+use crate::PersonError;
+use hdk::prelude::*;
+use zome_person_integrity::*;
+
+fn normalized_handle_path(handle: &str) -> Path {
+  Path::from(format!("handle.{}", handle.trim().to_lowercase()))
+}
+
+/// Registers `handle` as a WebFinger-style discovery anchor for the
+/// calling agent's person. Can be called directly, or via `handle` on
+/// `register_person` to anchor it at profile creation.
+///
+/// Duplicate rejection here is best-effort, not a hard guarantee: this
+/// `get_links` check is racy under concurrent registration of the same
+/// handle by two agents, and integrity validation only enforces the
+/// narrower invariant that no single agent can hold more than one
+/// `HandleToPerson` link (one-handle-per-agent), not one-agent-per-handle.
+/// `resolve_person` tie-breaks deterministically on the rare remaining
+/// collision so every caller still resolves to the same person.
+#[hdk_extern]
+pub fn register_person_handle(handle: String) -> ExternResult<()> {
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+  let person_links =
+    get_links(GetLinksInputBuilder::try_new(agent_pubkey, LinkTypes::AgentToPerson)?.build())?;
+  let person_link = person_links
+    .first()
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let path = normalized_handle_path(&handle);
+  let anchor_hash = path.path_entry_hash()?;
+
+  let existing = get_links(
+    GetLinksInputBuilder::try_new(anchor_hash.clone(), LinkTypes::HandleToPerson)?.build(),
+  )?;
+  if !existing.is_empty() {
+    return Err(PersonError::EntryOperationFailed("Handle is already registered".to_string()).into());
+  }
+
+  create_link(
+    anchor_hash,
+    person_link.target.clone(),
+    LinkTypes::HandleToPerson,
+    (),
+  )?;
+
+  Ok(())
+}
+
+/// Resolves a person's action hash from their handle, the way WebFinger
+/// resolves an actor from a human-readable identifier. If two agents ever
+/// won the registration race for the same handle, the earliest-timestamped
+/// link wins so every caller resolves to the same person.
+#[hdk_extern]
+pub fn resolve_person(handle: String) -> ExternResult<Option<ActionHash>> {
+  let path = normalized_handle_path(&handle);
+  let anchor_hash = path.path_entry_hash()?;
+
+  let mut links =
+    get_links(GetLinksInputBuilder::try_new(anchor_hash, LinkTypes::HandleToPerson)?.build())?;
+  links.sort_by_key(|link| link.timestamp);
+
+  Ok(
+    links
+      .into_iter()
+      .next()
+      .and_then(|link| link.target.into_action_hash()),
+  )
+}
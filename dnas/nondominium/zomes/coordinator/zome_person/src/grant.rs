@@ -0,0 +1,174 @@
+use crate::PersonError;
+use hdk::prelude::*;
+use zome_person_integrity::*;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GrantPrivateFieldAccessInput {
+  pub grantee: AgentPubKey,
+  pub fields: Vec<String>,
+  pub expiry: Option<Timestamp>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GrantPrivateFieldAccessOutput {
+  pub record: Record,
+  /// The caller must hand this to the grantee out-of-band; it is required
+  /// alongside the cap grant's `Assigned` access to call
+  /// `get_granted_person_data` remotely.
+  pub secret: CapSecret,
+}
+
+#[derive(Debug, Serialize, Deserialize, Default)]
+pub struct PartialPrivateData {
+  pub legal_name: Option<String>,
+  pub email: Option<String>,
+  pub phone: Option<String>,
+  pub address: Option<String>,
+  pub emergency_contact: Option<String>,
+  pub time_zone: Option<String>,
+  pub location: Option<String>,
+}
+
+fn cap_grant_tag(grant_hash: &ActionHash) -> String {
+  format!("private_data_grant:{}", grant_hash)
+}
+
+/// Writes a `PrivateDataGrant` describing the scoped share, then issues the
+/// grantee a capability grant (under a freshly generated secret) so their
+/// remote call to `get_granted_person_data` is authorized without exposing
+/// the rest of `PrivatePersonData`.
+#[hdk_extern]
+pub fn grant_private_field_access(
+  input: GrantPrivateFieldAccessInput,
+) -> ExternResult<GrantPrivateFieldAccessOutput> {
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+  let person_links =
+    get_links(GetLinksInputBuilder::try_new(agent_pubkey, LinkTypes::AgentToPerson)?.build())?;
+  let person_link = person_links
+    .first()
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let data_grant = PrivateDataGrant {
+    person: person_link.target.clone().into_action_hash().ok_or(PersonError::PersonNotFound)?,
+    grantee: input.grantee.clone(),
+    fields: input.fields,
+    expiry: input.expiry,
+  };
+
+  let grant_hash = create_entry(&EntryTypes::PrivateDataGrant(data_grant))?;
+  let record = get(grant_hash.clone(), GetOptions::default())?
+    .ok_or(PersonError::EntryOperationFailed("Failed to retrieve created grant".to_string()))?;
+
+  let secret_bytes = random_bytes(64)?;
+  let secret = CapSecret::try_from(secret_bytes.as_ref())
+    .map_err(|e| PersonError::Serialize(e.to_string()))?;
+
+  let mut assignees = BTreeSet::new();
+  assignees.insert(input.grantee);
+  create_cap_grant(CapGrantEntry {
+    tag: cap_grant_tag(&grant_hash),
+    access: CapAccess::Assigned { secret, assignees },
+    functions: GrantedFunctions::Listed(BTreeSet::from([(
+      zome_info()?.name,
+      "get_granted_person_data".into(),
+    )])),
+  })?;
+
+  Ok(GrantPrivateFieldAccessOutput { record, secret })
+}
+
+/// Called remotely by the grantee. Runs in the owner's context, so the
+/// full `PrivatePersonData` is locally readable; only the fields named in
+/// a live (non-expired, non-revoked) `PrivateDataGrant` for the caller are
+/// returned.
+#[hdk_extern]
+pub fn get_granted_person_data(person: ActionHash) -> ExternResult<PartialPrivateData> {
+  let caller = call_info()?.provenance;
+
+  let grant_links =
+    get_links(GetLinksInputBuilder::try_new(person.clone(), LinkTypes::PersonToPrivateData)?.build())?;
+
+  let now = sys_time()?;
+  let active_grant = live_private_data_grants()?
+    .into_iter()
+    .find(|grant| {
+      grant.person == person
+        && grant.grantee == caller
+        && grant.expiry.map(|expiry| now < expiry).unwrap_or(true)
+    })
+    .ok_or(PersonError::EntryOperationFailed(
+      "No active grant for this caller".to_string(),
+    ))?;
+
+  let private_data_link = grant_links.first().ok_or(PersonError::PersonNotFound)?;
+  let action_hash = private_data_link
+    .target
+    .clone()
+    .into_action_hash()
+    .ok_or(PersonError::PersonNotFound)?;
+  let private_data = get(action_hash, GetOptions::default())?
+    .ok_or(PersonError::PersonNotFound)?
+    .entry()
+    .to_app_option::<PrivatePersonData>()
+    .map_err(|e| PersonError::Serialize(e.to_string()))?
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let mut partial = PartialPrivateData::default();
+  for field in &active_grant.fields {
+    match field.as_str() {
+      "legal_name" => partial.legal_name = Some(private_data.legal_name.clone()),
+      "email" => partial.email = Some(private_data.email.clone()),
+      "phone" => partial.phone = private_data.phone.clone(),
+      "address" => partial.address = private_data.address.clone(),
+      "emergency_contact" => partial.emergency_contact = private_data.emergency_contact.clone(),
+      "time_zone" => partial.time_zone = private_data.time_zone.clone(),
+      "location" => partial.location = private_data.location.clone(),
+      _ => {}
+    }
+  }
+
+  Ok(partial)
+}
+
+/// `query` returns every action on the local chain regardless of CRUD
+/// status, so a grant entry's Create action would still be present and
+/// would still be picked up after a revoke. Re-resolving each candidate
+/// through `get`, which honors deletes, is what actually drops revoked
+/// grants from the result.
+fn live_private_data_grants() -> ExternResult<Vec<PrivateDataGrant>> {
+  let candidates = query(ChainQueryFilter::new().entry_type(EntryType::App(
+    zome_person_integrity::UnitEntryTypes::PrivateDataGrant.try_into()?,
+  )))?;
+
+  let mut grants = Vec::new();
+  for candidate in candidates {
+    let Some(live_record) = get(candidate.action_address().clone(), GetOptions::default())? else {
+      continue;
+    };
+    if let Ok(Some(grant)) = live_record.entry().to_app_option::<PrivateDataGrant>() {
+      grants.push(grant);
+    }
+  }
+
+  Ok(grants)
+}
+
+/// Deletes the `PrivateDataGrant` and withdraws the associated capability
+/// grant, immediately cutting off the grantee's access.
+#[hdk_extern]
+pub fn revoke_private_field_access(grant_hash: ActionHash) -> ExternResult<ActionHash> {
+  let delete_hash = delete_entry(grant_hash.clone())?;
+
+  let tag = cap_grant_tag(&grant_hash);
+  let cap_grants = query(ChainQueryFilter::new().entry_type(EntryType::CapGrant))?;
+  for candidate in cap_grants {
+    if let RecordEntry::Present(Entry::CapGrant(cap_grant)) = candidate.entry() {
+      if cap_grant.tag == tag {
+        delete_cap_grant(candidate.action_address().clone())?;
+        break;
+      }
+    }
+  }
+
+  Ok(delete_hash)
+}
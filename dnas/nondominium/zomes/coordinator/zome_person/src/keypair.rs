@@ -0,0 +1,165 @@
+use crate::PersonError;
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use hdk::prelude::*;
+use zome_person_integrity::*;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedClaim {
+  pub claim_bytes: Vec<u8>,
+  pub signature: Vec<u8>,
+}
+
+/// Generates the person's application-level signing keypair and links it
+/// from their `Person` entry. Called once, as part of registration.
+#[hdk_extern]
+pub fn create_person_keypair(_: ()) -> ExternResult<Record> {
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+  let person_links =
+    get_links(GetLinksInputBuilder::try_new(agent_pubkey, LinkTypes::AgentToPerson)?.build())?;
+  let person_link = person_links
+    .first()
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let seed: [u8; 32] = random_bytes(32)?
+    .as_ref()
+    .try_into()
+    .map_err(|_| PersonError::Serialize("Invalid signing seed length".to_string()))?;
+  let signing_key = SigningKey::from_bytes(&seed);
+
+  let private_keypair = PrivatePersonKeypair {
+    private_key: signing_key.to_bytes().to_vec(),
+  };
+  let private_keypair_hash = create_entry(&EntryTypes::PrivatePersonKeypair(private_keypair))?;
+
+  let public_keypair = PersonKeypair {
+    public_key: signing_key.verifying_key().to_bytes().to_vec(),
+  };
+  let public_keypair_hash = create_entry(&EntryTypes::PersonKeypair(public_keypair))?;
+  let record = get(public_keypair_hash.clone(), GetOptions::default())?.ok_or(
+    PersonError::EntryOperationFailed("Failed to retrieve created keypair".to_string()),
+  )?;
+
+  // Ties the public keypair to its matching private half so
+  // `private_signing_key_for` can resolve the correct key pair instead of
+  // guessing at the first `PrivatePersonKeypair` on the chain.
+  create_link(
+    public_keypair_hash.clone(),
+    private_keypair_hash,
+    LinkTypes::PersonKeypairToPrivateKeypair,
+    (),
+  )?;
+
+  create_link(
+    person_link.target.clone(),
+    public_keypair_hash,
+    LinkTypes::PersonToPublicKey,
+    (),
+  )?;
+
+  Ok(record)
+}
+
+/// Signs `claim_bytes` with the caller's private signing key, producing a
+/// claim that can be verified by anyone holding the corresponding
+/// `PersonKeypair` public key, independent of this DHT's authorship chain.
+#[hdk_extern]
+pub fn sign_profile_claim(claim_bytes: Vec<u8>) -> ExternResult<SignedClaim> {
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+  let person_links =
+    get_links(GetLinksInputBuilder::try_new(agent_pubkey, LinkTypes::AgentToPerson)?.build())?;
+  let person_link = person_links
+    .first()
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let keypair_links = get_links(
+    GetLinksInputBuilder::try_new(person_link.target.clone(), LinkTypes::PersonToPublicKey)?
+      .build(),
+  )?;
+  let private_key = private_signing_key_for(&keypair_links)?;
+
+  let signature = private_key.sign(&claim_bytes);
+
+  Ok(SignedClaim {
+    claim_bytes,
+    signature: signature.to_bytes().to_vec(),
+  })
+}
+
+/// Verifies a `SignedClaim` against a previously resolved public key,
+/// without needing access to the signer's source chain.
+#[hdk_extern]
+pub fn verify_profile_claim(input: (SignedClaim, Vec<u8>)) -> ExternResult<bool> {
+  let (claim, public_key) = input;
+
+  let verifying_key_bytes: [u8; 32] = public_key
+    .as_slice()
+    .try_into()
+    .map_err(|_| PersonError::Serialize("Invalid public key length".to_string()))?;
+  let verifying_key = VerifyingKey::from_bytes(&verifying_key_bytes)
+    .map_err(|e| PersonError::Serialize(e.to_string()))?;
+
+  let signature_bytes: [u8; 64] = claim
+    .signature
+    .as_slice()
+    .try_into()
+    .map_err(|_| PersonError::Serialize("Invalid signature length".to_string()))?;
+  let signature = ed25519_dalek::Signature::from_bytes(&signature_bytes);
+
+  Ok(verifying_key.verify(&claim.claim_bytes, &signature).is_ok())
+}
+
+fn private_signing_key_for(keypair_links: &[Link]) -> ExternResult<SigningKey> {
+  let keypair_link = keypair_links
+    .first()
+    .ok_or(PersonError::PersonNotFound)?;
+  let public_keypair_hash = keypair_link
+    .target
+    .clone()
+    .into_action_hash()
+    .ok_or(PersonError::PersonNotFound)?;
+  let public_keypair = get(public_keypair_hash.clone(), GetOptions::default())?
+    .ok_or(PersonError::PersonNotFound)?
+    .entry()
+    .to_app_option::<PersonKeypair>()
+    .map_err(|e| PersonError::Serialize(e.to_string()))?
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let private_keypair_links = get_links(
+    GetLinksInputBuilder::try_new(
+      public_keypair_hash,
+      LinkTypes::PersonKeypairToPrivateKeypair,
+    )?
+    .build(),
+  )?;
+  let private_keypair_link = private_keypair_links
+    .first()
+    .ok_or(PersonError::PersonNotFound)?;
+  let private_action_hash = private_keypair_link
+    .target
+    .clone()
+    .into_action_hash()
+    .ok_or(PersonError::PersonNotFound)?;
+  let private_keypair = get(private_action_hash, GetOptions::default())?
+    .ok_or(PersonError::PersonNotFound)?
+    .entry()
+    .to_app_option::<PrivatePersonKeypair>()
+    .map_err(|e| PersonError::Serialize(e.to_string()))?
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let private_key_bytes: [u8; 32] = private_keypair
+    .private_key
+    .try_into()
+    .map_err(|_| PersonError::Serialize("Stored private key has an invalid length".to_string()))?;
+  let signing_key = SigningKey::from_bytes(&private_key_bytes);
+
+  if signing_key.verifying_key().to_bytes().to_vec() != public_keypair.public_key {
+    return Err(
+      PersonError::Serialize(
+        "Resolved private key does not match the linked public keypair".to_string(),
+      )
+      .into(),
+    );
+  }
+
+  Ok(signing_key)
+}
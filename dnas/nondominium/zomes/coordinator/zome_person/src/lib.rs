@@ -0,0 +1,33 @@
+use hdk::prelude::*;
+
+pub mod discovery;
+pub mod grant;
+pub mod keypair;
+pub mod person;
+pub mod private_data;
+pub mod profile;
+pub mod role;
+
+pub use discovery::*;
+pub use grant::*;
+pub use keypair::*;
+pub use person::*;
+pub use private_data::*;
+pub use profile::*;
+pub use role::*;
+
+#[derive(thiserror::Error, Debug)]
+pub enum PersonError {
+  #[error("Entry operation failed: {0}")]
+  EntryOperationFailed(String),
+  #[error("Person not found")]
+  PersonNotFound,
+  #[error("Serialized bytes error: {0}")]
+  Serialize(String),
+}
+
+impl From<PersonError> for WasmError {
+  fn from(err: PersonError) -> Self {
+    wasm_error!(WasmErrorInner::Guest(err.to_string()))
+  }
+}
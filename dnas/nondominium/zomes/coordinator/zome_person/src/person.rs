@@ -0,0 +1,39 @@
+use crate::PersonError;
+use hdk::prelude::*;
+use zome_person_integrity::*;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PersonInput {
+  pub name: String,
+  pub avatar_url: Option<String>,
+  #[serde(default)]
+  pub bot_account: bool,
+  /// Optional WebFinger-style handle to register for discovery in the same
+  /// call as profile creation. See `discovery::register_person_handle` for
+  /// what "duplicate rejection" actually guarantees here.
+  pub handle: Option<String>,
+}
+
+#[hdk_extern]
+pub fn register_person(input: PersonInput) -> ExternResult<Record> {
+  let person = Person {
+    name: input.name,
+    avatar_url: input.avatar_url,
+    bot_account: input.bot_account,
+  };
+
+  let person_hash = create_entry(&EntryTypes::Person(person))?;
+  let record = get(person_hash.clone(), GetOptions::default())?
+    .ok_or(PersonError::EntryOperationFailed("Failed to retrieve created person".to_string()))?;
+
+  let agent_pubkey = agent_info()?.agent_initial_pubkey;
+  create_link(agent_pubkey, person_hash, LinkTypes::AgentToPerson, ())?;
+
+  crate::keypair::create_person_keypair(())?;
+
+  if let Some(handle) = input.handle {
+    crate::discovery::register_person_handle(handle)?;
+  }
+
+  Ok(record)
+}
@@ -11,6 +11,13 @@ pub struct PrivatePersonDataInput {
   pub emergency_contact: Option<String>,
   pub time_zone: Option<String>,
   pub location: Option<String>,
+  /// Display name to show on the public profile if `visibility.display_handle`
+  /// is set. Not part of `PrivatePersonData` since it is only ever meant to
+  /// be shown, never kept private.
+  pub display_handle: Option<String>,
+  /// Per-field flags controlling which fields are mirrored into the public
+  /// `PublicPersonProfile`. Omitted entirely, no public profile is created.
+  pub visibility: Option<PersonFieldVisibility>,
 }
 
 #[hdk_extern]
@@ -21,8 +28,8 @@ pub fn store_private_person_data(input: PrivatePersonDataInput) -> ExternResult<
     phone: input.phone,
     address: input.address,
     emergency_contact: input.emergency_contact,
-    time_zone: input.time_zone,
-    location: input.location,
+    time_zone: input.time_zone.clone(),
+    location: input.location.clone(),
   };
 
   let private_data_hash = create_entry(&EntryTypes::PrivatePersonData(private_data.clone()))?;
@@ -42,6 +49,25 @@ pub fn store_private_person_data(input: PrivatePersonDataInput) -> ExternResult<
       LinkTypes::PersonToPrivateData,
       (),
     )?;
+
+    if let Some(visibility) = input.visibility {
+      let public_profile = PublicPersonProfile {
+        display_handle: if visibility.display_handle {
+          input.display_handle
+        } else {
+          None
+        },
+        time_zone: if visibility.time_zone { input.time_zone } else { None },
+        location: if visibility.location { input.location } else { None },
+      };
+      let public_profile_hash = create_entry(&EntryTypes::PublicPersonProfile(public_profile))?;
+      create_link(
+        person_link.target.clone(),
+        public_profile_hash,
+        LinkTypes::PersonToPublicProfile,
+        (),
+      )?;
+    }
   }
 
   Ok(record)
@@ -0,0 +1,32 @@
+use crate::PersonError;
+use hdk::prelude::*;
+use zome_person_integrity::*;
+
+/// Returns the safe, public-only view of a person's profile. Any agent may
+/// call this; the fields returned are exactly those the owner marked public
+/// when calling `store_private_person_data` — the full `PrivatePersonData`
+/// entry is never touched here.
+#[hdk_extern]
+pub fn get_person_profile(person_hash: ActionHash) -> ExternResult<PublicPersonProfile> {
+  let profile_links = get_links(
+    GetLinksInputBuilder::try_new(person_hash, LinkTypes::PersonToPublicProfile)?.build(),
+  )?;
+
+  let profile_link = profile_links
+    .first()
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let action_hash = profile_link
+    .target
+    .clone()
+    .into_action_hash()
+    .ok_or(PersonError::PersonNotFound)?;
+
+  let record = get(action_hash, GetOptions::default())?.ok_or(PersonError::PersonNotFound)?;
+
+  record
+    .entry()
+    .to_app_option::<PublicPersonProfile>()
+    .map_err(|e| PersonError::Serialize(e.to_string()))?
+    .ok_or_else(|| PersonError::EntryOperationFailed("No public profile for person".to_string()).into())
+}
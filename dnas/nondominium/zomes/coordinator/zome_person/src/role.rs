@@ -0,0 +1,153 @@
+use crate::PersonError;
+use hdk::prelude::*;
+use zome_person_integrity::*;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssignPersonRoleInput {
+  pub person: ActionHash,
+  pub role: RoleLevel,
+}
+
+/// Authors a `PersonRole` for `input.person`, granted by the calling agent.
+/// The caller's own `PersonRole` record is resolved and threaded through as
+/// `granting_role` so integrity validation can follow the proof back to the
+/// authority it actually confers, rather than trusting the caller's word.
+#[hdk_extern]
+pub fn assign_person_role(input: AssignPersonRoleInput) -> ExternResult<Record> {
+  let caller = agent_info()?.agent_initial_pubkey;
+  let caller_role_records = get_person_role_records(caller.clone())?;
+
+  let granting_role = if matches!(input.role, RoleLevel::Admin | RoleLevel::Moderator) {
+    match caller_role_records
+      .iter()
+      .find(|(_, role)| role.role == RoleLevel::Admin)
+    {
+      Some(admin_record) => Some(admin_record.0.clone()),
+      None if matches!(input.role, RoleLevel::Admin) && is_progenitor()? => None,
+      None => {
+        return Err(
+          PersonError::EntryOperationFailed(
+            "Only an Admin may assign Admin or Moderator roles".to_string(),
+          )
+          .into(),
+        )
+      }
+    }
+  } else if matches!(input.role, RoleLevel::Advocate) {
+    let record = caller_role_records
+      .iter()
+      .find(|(_, role)| matches!(role.role, RoleLevel::Admin | RoleLevel::Moderator))
+      .ok_or(PersonError::EntryOperationFailed(
+        "Only an Admin or Moderator may assign the Advocate role".to_string(),
+      ))?;
+    Some(record.0.clone())
+  } else {
+    None
+  };
+
+  let role = PersonRole {
+    person: input.person.clone(),
+    role: input.role,
+    granted_by: caller,
+    granting_role,
+  };
+
+  let role_hash = create_entry(&EntryTypes::PersonRole(role))?;
+  let record = get(role_hash.clone(), GetOptions::default())?
+    .ok_or(PersonError::EntryOperationFailed("Failed to retrieve created role".to_string()))?;
+
+  create_link(input.person, role_hash, LinkTypes::PersonToRole, ())?;
+
+  Ok(record)
+}
+
+/// Revokes a role. Mirrors `validate_delete_person_role` exactly: only the
+/// original granter, or the agent whose own proven role authorized that
+/// granter, may revoke — there is no separate "any Admin" allowance here,
+/// since integrity doesn't grant one either and the two must agree or a
+/// caller that passes this pre-check would still fail at commit-time
+/// validation. The stale `PersonToRole` link is removed so
+/// `get_person_roles` doesn't keep surfacing a role whose backing entry is
+/// gone.
+#[hdk_extern]
+pub fn revoke_person_role(original_role_hash: ActionHash) -> ExternResult<ActionHash> {
+  let caller = agent_info()?.agent_initial_pubkey;
+  let record = get(original_role_hash.clone(), GetOptions::default())?
+    .ok_or(PersonError::EntryOperationFailed("Role not found".to_string()))?;
+  let role = record
+    .entry()
+    .to_app_option::<PersonRole>()
+    .map_err(|e| PersonError::Serialize(e.to_string()))?
+    .ok_or(PersonError::EntryOperationFailed("Role not found".to_string()))?;
+
+  let caller_is_granter = role.granted_by == caller;
+  let caller_authorized_the_granter = match &role.granting_role {
+    Some(granting_role_hash) => get(granting_role_hash.clone(), GetOptions::default())?
+      .map(|r| *r.action().author() == caller)
+      .unwrap_or(false),
+    None => false,
+  };
+
+  if !caller_is_granter && !caller_authorized_the_granter {
+    return Err(
+      PersonError::EntryOperationFailed(
+        "Only the granting agent, or the agent who authorized them, may revoke this role"
+          .to_string(),
+      )
+      .into(),
+    );
+  }
+
+  let delete_hash = delete_entry(original_role_hash.clone())?;
+
+  let role_links =
+    get_links(GetLinksInputBuilder::try_new(role.person, LinkTypes::PersonToRole)?.build())?;
+  for link in role_links {
+    if link.target.into_action_hash().as_ref() == Some(&original_role_hash) {
+      delete_link(link.create_link_hash)?;
+    }
+  }
+
+  Ok(delete_hash)
+}
+
+fn is_progenitor() -> ExternResult<bool> {
+  let properties: PersonDnaProperties = dna_info()?.modifiers.properties.try_into()?;
+  Ok(properties.progenitor_pubkey == agent_info()?.agent_initial_pubkey)
+}
+
+fn get_person_role_records(agent: AgentPubKey) -> ExternResult<Vec<(ActionHash, PersonRole)>> {
+  let person_links =
+    get_links(GetLinksInputBuilder::try_new(agent, LinkTypes::AgentToPerson)?.build())?;
+  let Some(person_link) = person_links.first() else {
+    return Ok(Vec::new());
+  };
+
+  let role_links = get_links(
+    GetLinksInputBuilder::try_new(person_link.target.clone(), LinkTypes::PersonToRole)?.build(),
+  )?;
+
+  let mut roles = Vec::new();
+  for link in role_links {
+    let Some(action_hash) = link.target.into_action_hash() else {
+      continue;
+    };
+    if let Some(record) = get(action_hash.clone(), GetOptions::default())? {
+      if let Ok(Some(role)) = record.entry().to_app_option::<PersonRole>() {
+        roles.push((action_hash, role));
+      }
+    }
+  }
+
+  Ok(roles)
+}
+
+#[hdk_extern]
+pub fn get_person_roles(agent: AgentPubKey) -> ExternResult<Vec<PersonRole>> {
+  Ok(
+    get_person_role_records(agent)?
+      .into_iter()
+      .map(|(_, role)| role)
+      .collect(),
+  )
+}
@@ -0,0 +1,42 @@
+use hdi::prelude::*;
+
+/// An owner-signed record of which `PrivatePersonData` fields are shared
+/// with `grantee`, and for how long. The private entry itself is never
+/// copied anywhere; this only describes the scope of a capability grant
+/// the owner has issued so `get_granted_person_data` knows what it may
+/// disclose when the grantee calls it.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct PrivateDataGrant {
+  pub person: ActionHash,
+  pub grantee: AgentPubKey,
+  pub fields: Vec<String>,
+  pub expiry: Option<Timestamp>,
+}
+
+pub fn validate_create_private_data_grant(
+  grant: PrivateDataGrant,
+  action: &Action,
+) -> ExternResult<ValidateCallbackResult> {
+  if let Some(expiry) = grant.expiry {
+    if expiry <= action.timestamp() {
+      return Ok(ValidateCallbackResult::Invalid(
+        "Grant expiry must be in the future".into(),
+      ));
+    }
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Only the agent who authored the grant may revoke it.
+pub fn validate_delete_private_data_grant(
+  original_action: EntryCreationAction,
+  delete_action: &Action,
+) -> ExternResult<ValidateCallbackResult> {
+  if original_action.author() != delete_action.author() {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Only the granting agent may revoke a PrivateDataGrant".into(),
+    ));
+  }
+  Ok(ValidateCallbackResult::Valid)
+}
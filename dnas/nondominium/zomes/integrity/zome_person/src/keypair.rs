@@ -0,0 +1,23 @@
+use hdi::prelude::*;
+
+/// The public half of a person's application-level signing keypair. The
+/// matching private key is held in a private entry and never leaves the
+/// owning agent's source chain.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct PersonKeypair {
+  pub public_key: Vec<u8>,
+}
+
+#[hdk_entry_helper]
+#[entry_def(visibility = "private")]
+#[derive(Clone)]
+pub struct PrivatePersonKeypair {
+  pub private_key: Vec<u8>,
+}
+
+pub fn validate_create_person_keypair(
+  _keypair: PersonKeypair,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Valid)
+}
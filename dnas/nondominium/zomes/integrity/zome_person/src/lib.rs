@@ -0,0 +1,127 @@
+use hdi::prelude::*;
+
+mod grant;
+mod keypair;
+mod person;
+mod private_data;
+mod profile;
+mod role;
+
+pub use grant::*;
+pub use keypair::*;
+pub use person::*;
+pub use private_data::*;
+pub use profile::*;
+pub use role::*;
+
+#[hdk_entry_types]
+#[unit_enum(UnitEntryTypes)]
+pub enum EntryTypes {
+  Person(Person),
+  PrivatePersonData(PrivatePersonData),
+  PublicPersonProfile(PublicPersonProfile),
+  PersonKeypair(PersonKeypair),
+  PrivatePersonKeypair(PrivatePersonKeypair),
+  PersonRole(PersonRole),
+  PrivateDataGrant(PrivateDataGrant),
+  #[entry_type(visibility = "public")]
+  Path(Path),
+}
+
+#[hdk_link_types]
+pub enum LinkTypes {
+  AgentToPerson,
+  PersonToPrivateData,
+  PersonToPublicProfile,
+  PersonToPublicKey,
+  PersonKeypairToPrivateKeypair,
+  PersonToRole,
+  HandleToPerson,
+}
+
+#[hdk_extern]
+pub fn validate(op: Op) -> ExternResult<ValidateCallbackResult> {
+  match op.flattened::<EntryTypes, LinkTypes>()? {
+    FlatOp::StoreEntry(OpEntry::CreateEntry { app_entry, action }) => match app_entry {
+      EntryTypes::Person(person) => person::validate_create_person(person),
+      EntryTypes::PrivatePersonData(private_data) => {
+        private_data::validate_create_private_person_data(private_data)
+      }
+      EntryTypes::PublicPersonProfile(profile) => {
+        profile::validate_create_public_person_profile(profile)
+      }
+      EntryTypes::PersonKeypair(keypair) => keypair::validate_create_person_keypair(keypair),
+      EntryTypes::PrivatePersonKeypair(_) => Ok(ValidateCallbackResult::Valid),
+      EntryTypes::PersonRole(person_role) => {
+        role::validate_create_person_role(person_role, action.author().clone())
+      }
+      EntryTypes::PrivateDataGrant(data_grant) => {
+        grant::validate_create_private_data_grant(data_grant, &Action::Create(action))
+      }
+      EntryTypes::Path(_) => Ok(ValidateCallbackResult::Valid),
+    },
+    FlatOp::StoreEntry(OpEntry::DeleteEntry {
+      original_action,
+      action,
+      ..
+    }) => {
+      let original_entry_hash = original_action
+        .entry_hash()
+        .cloned()
+        .ok_or(wasm_error!(WasmErrorInner::Guest(
+          "Delete of an entryless action".to_string()
+        )))?;
+      let original_entry = must_get_entry(original_entry_hash)?;
+      if PrivateDataGrant::try_from(original_entry.content.clone()).is_ok() {
+        return grant::validate_delete_private_data_grant(
+          original_action,
+          &Action::Delete(action),
+        );
+      }
+      if let Ok(person_role) = PersonRole::try_from(original_entry.content) {
+        return role::validate_delete_person_role(person_role, action.author().clone());
+      }
+      Ok(ValidateCallbackResult::Valid)
+    }
+    FlatOp::RegisterCreateLink {
+      link_type: LinkTypes::HandleToPerson,
+      action,
+      ..
+    } => validate_create_handle_link(action),
+    _ => Ok(ValidateCallbackResult::Valid),
+  }
+}
+
+/// A single DHT validator can only ever see the one `CreateLink` op it is
+/// asked to validate, not the full set of links any other agent may be
+/// concurrently writing to the same handle anchor — so this cannot catch
+/// every race. What it *can* enforce deterministically is that no single
+/// agent ever holds more than one `HandleToPerson` link, which rules out
+/// handle squatting/rotation and narrows the remaining race to "two
+/// distinct agents register the same handle in the same moment", a case
+/// the coordinator's pre-registration `get_links` check is meant to catch.
+fn validate_create_handle_link(action: CreateLink) -> ExternResult<ValidateCallbackResult> {
+  let filter = ChainQueryFilter::new().action_type(ActionType::CreateLink);
+  let activity = must_get_agent_activity(action.author.clone(), filter)?;
+  let handle_link_type: LinkType = LinkTypes::HandleToPerson.try_into()?;
+
+  let handle_link_count = activity
+    .into_iter()
+    .filter(|activity_item| {
+      matches!(
+        &activity_item.action.hashed.content,
+        Action::CreateLink(create_link)
+          if create_link.zome_index == action.zome_index
+            && create_link.link_type == handle_link_type
+      )
+    })
+    .count();
+
+  if handle_link_count > 1 {
+    return Ok(ValidateCallbackResult::Invalid(
+      "An agent may only register a single handle".into(),
+    ));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,16 @@
+use hdi::prelude::*;
+
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct Person {
+  pub name: String,
+  pub avatar_url: Option<String>,
+  /// Gates bot-specific behavior in downstream zomes (e.g. rate limits,
+  /// disclosure in the public profile) without introducing a separate
+  /// entry type.
+  pub bot_account: bool,
+}
+
+pub fn validate_create_person(_person: Person) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,20 @@
+use hdi::prelude::*;
+
+#[hdk_entry_helper]
+#[entry_def(visibility = "private")]
+#[derive(Clone)]
+pub struct PrivatePersonData {
+  pub legal_name: String,
+  pub email: String,
+  pub phone: Option<String>,
+  pub address: Option<String>,
+  pub emergency_contact: Option<String>,
+  pub time_zone: Option<String>,
+  pub location: Option<String>,
+}
+
+pub fn validate_create_private_person_data(
+  _private_data: PrivatePersonData,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Valid)
+}
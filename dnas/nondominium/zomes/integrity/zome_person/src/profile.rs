@@ -0,0 +1,28 @@
+use hdi::prelude::*;
+
+/// Per-field visibility flags an owner sets on their private data when
+/// building the public-facing profile. `true` means the field is included
+/// in `PublicPersonProfile`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct PersonFieldVisibility {
+  pub time_zone: bool,
+  pub location: bool,
+  pub display_handle: bool,
+}
+
+/// A Lemmy `PersonSafe`-style projection: only the fields the owner has
+/// marked public ever land in this entry, so it is safe to hand to any
+/// requesting agent.
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct PublicPersonProfile {
+  pub display_handle: Option<String>,
+  pub time_zone: Option<String>,
+  pub location: Option<String>,
+}
+
+pub fn validate_create_public_person_profile(
+  _profile: PublicPersonProfile,
+) -> ExternResult<ValidateCallbackResult> {
+  Ok(ValidateCallbackResult::Valid)
+}
@@ -0,0 +1,151 @@
+use hdi::prelude::*;
+
+/// DNA property carrying the one agent allowed to bootstrap the authority
+/// model by self-granting the first Admin role.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PersonDnaProperties {
+  pub progenitor_pubkey: AgentPubKey,
+}
+
+fn is_progenitor(agent: &AgentPubKey) -> ExternResult<bool> {
+  let properties: PersonDnaProperties = dna_info()?.modifiers.properties.try_into()?;
+  Ok(properties.progenitor_pubkey == *agent)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoleLevel {
+  Admin,
+  Moderator,
+  Advocate,
+  Member,
+}
+
+#[hdk_entry_helper]
+#[derive(Clone)]
+pub struct PersonRole {
+  pub person: ActionHash,
+  pub role: RoleLevel,
+  pub granted_by: AgentPubKey,
+  /// Action hash of the granter's own `PersonRole` entry, proving they
+  /// held sufficient authority at grant time. `None` is only valid for a
+  /// `Member` role, or for the progenitor's bootstrap `Admin` role.
+  pub granting_role: Option<ActionHash>,
+}
+
+/// Whether `role_hash` has since been deleted by any of `possible_deleters`
+/// — the only agents `validate_delete_person_role` ever permits to author
+/// that delete, so this is enough to determine liveness deterministically.
+fn role_is_revoked(
+  role_hash: &ActionHash,
+  possible_deleters: &[AgentPubKey],
+) -> ExternResult<bool> {
+  for deleter in possible_deleters {
+    let activity =
+      must_get_agent_activity(deleter.clone(), ChainQueryFilter::new().include_entries(false))?;
+    let deleted = activity.into_iter().any(|activity_item| {
+      matches!(
+        &activity_item.action.hashed.content,
+        Action::Delete(delete) if delete.deletes_address == *role_hash
+      )
+    });
+    if deleted {
+      return Ok(true);
+    }
+  }
+  Ok(false)
+}
+
+/// Resolves `granting_role` (if any) and checks `author` actually *holds*
+/// it — i.e. they are the agent who registered the `Person` the role was
+/// granted to — and that it carries enough authority for `role.role`, and
+/// that it hasn't since been revoked. Checking the granting record's
+/// *authorship* instead of *possession* would only ever let the original
+/// granter (ultimately the progenitor) assign roles, since a delegated
+/// Admin's own Admin role is authored by whoever granted it to them, not
+/// by the delegate.
+pub fn validate_create_person_role(
+  role: PersonRole,
+  author: AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  if matches!(role.role, RoleLevel::Member) && role.granting_role.is_none() {
+    return Ok(ValidateCallbackResult::Valid);
+  }
+
+  let Some(granting_role_hash) = role.granting_role.clone() else {
+    if matches!(role.role, RoleLevel::Admin) && is_progenitor(&author)? {
+      return Ok(ValidateCallbackResult::Valid);
+    }
+    return Ok(ValidateCallbackResult::Invalid(
+      "Granting a Moderator, Advocate or Admin role requires a granting_role proof".into(),
+    ));
+  };
+
+  let granting_record = must_get_valid_record(granting_role_hash.clone())?;
+
+  let granter_role: PersonRole = granting_record
+    .entry()
+    .to_app_option()
+    .map_err(|e| wasm_error!(WasmErrorInner::Guest(e.to_string())))?
+    .ok_or(wasm_error!(WasmErrorInner::Guest(
+      "granting_role proof does not reference a PersonRole entry".to_string()
+    )))?;
+
+  let granter_person_record = must_get_valid_record(granter_role.person.clone())?;
+  if *granter_person_record.action().author() != author {
+    return Ok(ValidateCallbackResult::Invalid(
+      "granting_role proof is not held by the agent assigning the new role".into(),
+    ));
+  }
+
+  let mut possible_deleters = vec![granter_role.granted_by.clone()];
+  if let Some(grandparent_hash) = granter_role.granting_role.clone() {
+    if let Ok(grandparent_record) = must_get_valid_record(grandparent_hash) {
+      possible_deleters.push(grandparent_record.action().author().clone());
+    }
+  }
+  if role_is_revoked(&granting_role_hash, &possible_deleters)? {
+    return Ok(ValidateCallbackResult::Invalid(
+      "granting_role proof has been revoked".into(),
+    ));
+  }
+
+  let authority_sufficient = match role.role {
+    RoleLevel::Admin | RoleLevel::Moderator => granter_role.role == RoleLevel::Admin,
+    RoleLevel::Advocate => matches!(granter_role.role, RoleLevel::Admin | RoleLevel::Moderator),
+    RoleLevel::Member => true,
+  };
+
+  if !authority_sufficient {
+    return Ok(ValidateCallbackResult::Invalid(
+      "Granting agent's proven role does not confer enough authority for this grant".into(),
+    ));
+  }
+
+  Ok(ValidateCallbackResult::Valid)
+}
+
+/// Only the original granter, or the agent whose own proven role granted
+/// *them* their authority, may revoke a `PersonRole`. There is no general
+/// "any Admin" escape hatch here; the coordinator's pre-check must mirror
+/// exactly these two cases or calls will pass the pre-check and then fail
+/// at commit-time validation.
+pub fn validate_delete_person_role(
+  original_role: PersonRole,
+  deleter: AgentPubKey,
+) -> ExternResult<ValidateCallbackResult> {
+  if original_role.granted_by == deleter {
+    return Ok(ValidateCallbackResult::Valid);
+  }
+
+  if let Some(granting_role_hash) = original_role.granting_role {
+    if let Ok(granting_record) = must_get_valid_record(granting_role_hash) {
+      if *granting_record.action().author() == deleter {
+        return Ok(ValidateCallbackResult::Valid);
+      }
+    }
+  }
+
+  Ok(ValidateCallbackResult::Invalid(
+    "Only the original granter, or the agent who authorized them, may revoke a PersonRole".into(),
+  ))
+}